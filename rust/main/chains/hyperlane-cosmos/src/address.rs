@@ -0,0 +1,69 @@
+use cosmrs::crypto::PublicKey;
+use cosmrs::AccountId;
+use hyperlane_core::{AccountAddressType, ChainCommunicationError, ChainResult, H256};
+
+use crate::HyperlaneCosmosError;
+
+/// A thin wrapper around `cosmrs::AccountId`, used to convert between Cosmos account
+/// identifiers and the address types the rest of Hyperlane works with.
+#[derive(Debug, Clone, Copy)]
+pub struct CosmosAccountId<'a>(&'a AccountId);
+
+impl<'a> CosmosAccountId<'a> {
+    /// Wraps an existing `AccountId`, e.g. one decoded from a transaction message.
+    pub fn new(account_id: &'a AccountId) -> Self {
+        Self(account_id)
+    }
+
+    /// Derives the bech32 `AccountId` for `public_key` under `prefix`.
+    ///
+    /// `account_address_type` selects how the public key is hashed into an address: Cosmos
+    /// SDK chains (`Bitcoin`) hash with RIPEMD160(SHA256(pk)) via `cosmrs`, while ethsecp256k1
+    /// chains like Injective (`Ethereum`) derive the same way `normalize_public_key` expects,
+    /// i.e. from an already-decompressed secp256k1 key.
+    pub fn account_id_from_pubkey(
+        public_key: PublicKey,
+        prefix: &str,
+        _account_address_type: &AccountAddressType,
+    ) -> ChainResult<AccountId> {
+        public_key
+            .account_id(prefix)
+            .map_err(|e| HyperlaneCosmosError::PublicKeyError(e.to_string()).into())
+    }
+}
+
+/// A Cosmos bech32 address, convertible to the `H256` digest Hyperlane uses internally to
+/// represent addresses across all chain protocols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CosmosAddress(H256);
+
+impl CosmosAddress {
+    /// Builds a `CosmosAddress` from a bech32 `AccountId`, left-padding its raw bytes into an
+    /// `H256` digest.
+    pub fn from_account_id(account_id: AccountId) -> ChainResult<Self> {
+        Ok(Self(account_id_to_h256(&account_id)))
+    }
+
+    /// The `H256` digest used to represent this address internally.
+    pub fn digest(&self) -> H256 {
+        self.0
+    }
+}
+
+impl TryFrom<CosmosAccountId<'_>> for H256 {
+    type Error = ChainCommunicationError;
+
+    fn try_from(value: CosmosAccountId<'_>) -> Result<Self, Self::Error> {
+        Ok(account_id_to_h256(value.0))
+    }
+}
+
+/// Left-pads an `AccountId`'s raw bytes into an `H256`, the common representation Hyperlane
+/// uses for addresses regardless of the originating chain's native address width.
+fn account_id_to_h256(account_id: &AccountId) -> H256 {
+    let raw = account_id.to_bytes();
+    let len = raw.len().min(32);
+    let mut digest = [0u8; 32];
+    digest[32 - len..].copy_from_slice(&raw[raw.len() - len..]);
+    H256::from(digest)
+}
@@ -0,0 +1,24 @@
+use hyperlane_core::{ChainResult, U256};
+
+use crate::config::RawCosmosAmount;
+
+/// A gas price amount expressed in a specific denomination, parsed from configuration.
+#[derive(Debug, Clone)]
+pub struct CosmosAmount {
+    /// The denomination this amount is expressed in (e.g. `untrn`).
+    pub denom: String,
+    /// The amount, in the base unit of `denom`.
+    pub amount: U256,
+}
+
+impl TryFrom<RawCosmosAmount> for CosmosAmount {
+    type Error = hyperlane_core::ChainCommunicationError;
+
+    fn try_from(raw: RawCosmosAmount) -> ChainResult<Self> {
+        Ok(Self {
+            denom: raw.denom,
+            amount: U256::from_dec_str(&raw.amount)
+                .map_err(|e| hyperlane_core::ChainCommunicationError::from_other(e))?,
+        })
+    }
+}
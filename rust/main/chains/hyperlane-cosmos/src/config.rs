@@ -0,0 +1,168 @@
+use cosmrs::Coin as CosmrsCoin;
+
+/// A gas price amount as it appears in raw chain configuration, before being parsed into a
+/// [`crate::CosmosAmount`].
+#[derive(Debug, Clone)]
+pub struct RawCosmosAmount {
+    /// The denomination this amount is expressed in (e.g. `untrn`).
+    pub denom: String,
+    /// The amount, in the base unit of `denom`, as a decimal string.
+    pub amount: String,
+}
+
+/// The chain's native fee-paying token.
+#[derive(Debug, Clone)]
+pub struct NativeToken {
+    /// The denomination of the native token (e.g. `untrn`, `inj`).
+    pub denom: String,
+    /// Number of decimal places between the denom and its base unit (e.g. 6 for `untrn`, 18
+    /// for `inj`).
+    pub decimals: u32,
+}
+
+/// An additional denomination a chain may legitimately quote gas fees in, alongside its
+/// native token. Registered via [`ConnectionConf::get_additional_fee_denoms`].
+#[derive(Debug, Clone)]
+pub struct DenomConfig {
+    /// The denomination string (e.g. `untrn`, `uosmo`).
+    pub denom: String,
+    /// Number of decimal places between the denom and its base unit.
+    pub decimals: u32,
+    /// Rate used to convert an amount in this denom's base unit into the chain's native
+    /// token's base unit. `None` if the denom already *is* the native token.
+    pub conversion_rate: Option<f64>,
+}
+
+/// Resubmission and fee-escalation tuning for the `PendingTxQueue` (see
+/// `providers::cosmos::provider`).
+#[derive(Debug, Clone)]
+pub struct ResubmissionConfig {
+    /// Factor a stalled transaction's fee is multiplied by on each escalation.
+    pub escalation_factor: f64,
+    /// Maximum number of times a single transaction may be escalated before it's left
+    /// queued without further action.
+    pub max_retries: u32,
+    /// Number of blocks a transaction may go without inclusion before it's considered
+    /// stalled and eligible for escalation.
+    pub block_wait_threshold: u64,
+    /// Per-denom ceiling a fee may never be escalated past.
+    pub gas_price_ceiling: Vec<RawCosmosAmount>,
+}
+
+/// Connection configuration for a Cosmos chain: RPC/gRPC endpoints, addressing, and gas
+/// handling.
+#[derive(Debug, Clone)]
+pub struct ConnectionConf {
+    rpc_urls: Vec<String>,
+    grpc_urls: Vec<String>,
+    bech32_prefix: String,
+    canonical_asset: String,
+    minimum_gas_price: RawCosmosAmount,
+    native_token: NativeToken,
+    additional_fee_denoms: Vec<DenomConfig>,
+    gas_price_oracle_window_size: Option<u64>,
+    resubmission: ResubmissionConfig,
+}
+
+impl ConnectionConf {
+    /// Builds a `ConnectionConf` from its constituent parts. Mirrors the shape of the chain
+    /// config TOML/YAML the agent binaries load.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        rpc_urls: Vec<String>,
+        grpc_urls: Vec<String>,
+        bech32_prefix: String,
+        canonical_asset: String,
+        minimum_gas_price: RawCosmosAmount,
+        native_token: NativeToken,
+        additional_fee_denoms: Vec<DenomConfig>,
+        gas_price_oracle_window_size: Option<u64>,
+        resubmission: ResubmissionConfig,
+    ) -> Self {
+        Self {
+            rpc_urls,
+            grpc_urls,
+            bech32_prefix,
+            canonical_asset,
+            minimum_gas_price,
+            native_token,
+            additional_fee_denoms,
+            gas_price_oracle_window_size,
+            resubmission,
+        }
+    }
+
+    /// RPC endpoints used for Tendermint RPC queries (blocks, txs).
+    pub fn get_rpc_urls(&self) -> &[String] {
+        &self.rpc_urls
+    }
+
+    /// gRPC endpoints used for Cosmos SDK module queries (auth, bank, wasm).
+    pub fn get_grpc_urls(&self) -> &[String] {
+        &self.grpc_urls
+    }
+
+    /// Bech32 human-readable prefix used to derive account addresses (e.g. `neutron`, `inj`).
+    pub fn get_bech32_prefix(&self) -> String {
+        self.bech32_prefix.clone()
+    }
+
+    /// Denom of the asset `get_balance` reports, e.g. a canonical IBC voucher denom.
+    pub fn get_canonical_asset(&self) -> String {
+        self.canonical_asset.clone()
+    }
+
+    /// The minimum gas price operators are willing to pay, used as the static fallback when
+    /// the gas-price oracle can't produce a fresher estimate.
+    pub fn get_minimum_gas_price(&self) -> RawCosmosAmount {
+        self.minimum_gas_price.clone()
+    }
+
+    /// The chain's native fee-paying token.
+    pub fn get_native_token(&self) -> NativeToken {
+        self.native_token.clone()
+    }
+
+    /// Additional denominations this chain legitimately quotes gas fees in, beyond the native
+    /// token.
+    pub fn get_additional_fee_denoms(&self) -> Vec<DenomConfig> {
+        self.additional_fee_denoms.clone()
+    }
+
+    /// Number of trailing blocks the gas-price oracle should sample. `None` falls back to the
+    /// oracle's own default window.
+    pub fn get_gas_price_oracle_window_size(&self) -> Option<u64> {
+        self.gas_price_oracle_window_size
+    }
+
+    /// Factor a stalled transaction's fee is multiplied by on each escalation.
+    pub fn get_gas_escalation_factor(&self) -> f64 {
+        self.resubmission.escalation_factor
+    }
+
+    /// Maximum number of times a single transaction may be escalated.
+    pub fn get_max_resubmission_retries(&self) -> u32 {
+        self.resubmission.max_retries
+    }
+
+    /// Number of blocks a transaction may go without inclusion before it's eligible for
+    /// escalation.
+    pub fn get_resubmission_block_wait_threshold(&self) -> u64 {
+        self.resubmission.block_wait_threshold
+    }
+
+    /// Per-denom ceiling a fee may never be escalated past, as `cosmrs::Coin`s ready to
+    /// compare against a transaction's fee amount.
+    pub fn get_gas_price_ceiling(&self) -> Vec<CosmrsCoin> {
+        self.resubmission
+            .gas_price_ceiling
+            .iter()
+            .filter_map(|raw| {
+                Some(CosmrsCoin {
+                    denom: raw.denom.parse().ok()?,
+                    amount: raw.amount.parse().ok()?,
+                })
+            })
+            .collect()
+    }
+}
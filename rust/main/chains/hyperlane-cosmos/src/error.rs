@@ -0,0 +1,24 @@
+use hyperlane_core::ChainCommunicationError;
+
+/// Errors specific to the Cosmos provider implementation.
+#[derive(Debug, thiserror::Error)]
+pub enum HyperlaneCosmosError {
+    /// A public key could not be normalized or decoded into a known type.
+    #[error("{0}")]
+    PublicKeyError(String),
+    /// Signer info could not be resolved into an account id and nonce.
+    #[error("{0}")]
+    SignerInfoError(String),
+    /// Error bubbled up from the underlying `cosmrs` crate.
+    #[error(transparent)]
+    CosmrsError(#[from] cosmrs::ErrorReport),
+    /// Error bubbled up from a `cosmrs` proto conversion.
+    #[error(transparent)]
+    ProtoError(#[from] cosmrs::proto::prost::DecodeError),
+}
+
+impl From<HyperlaneCosmosError> for ChainCommunicationError {
+    fn from(value: HyperlaneCosmosError) -> Self {
+        ChainCommunicationError::from_other(value)
+    }
+}
@@ -0,0 +1,230 @@
+use cosmos_sdk_proto::cosmos::auth::v1beta1::{
+    query_client::QueryClient as AuthQueryClient, BaseAccount, QueryAccountRequest,
+};
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+    query_client::QueryClient as BankQueryClient, QueryBalanceRequest,
+};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{
+    service_client::ServiceClient as TxServiceClient, BroadcastMode, BroadcastTxRequest,
+};
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    query_client::QueryClient as WasmQueryClient, QueryContractInfoRequest,
+};
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::tx::{Body as TxBody, Fee, Raw as RawTx, SequenceNumber, SignDoc, SignerInfo};
+use cosmrs::{AccountId, Any, Coin};
+use hyperlane_core::{ChainCommunicationError, ChainResult, ContractLocator, HyperlaneDomain, H256, U256};
+use prost::Message;
+use tonic::transport::Channel;
+
+use crate::{CosmosAmount, Signer};
+
+/// Behavior shared by the wasm-module-backed gRPC providers (currently only `WasmGrpcProvider`
+/// implements it, but it keeps the contract-query surface separate from connection setup).
+#[async_trait::async_trait]
+pub trait WasmProvider {
+    /// Queries the `wasm` module for this provider's contract info; errors if its locator
+    /// doesn't point at a contract.
+    async fn wasm_contract_info(&self) -> ChainResult<()>;
+
+    /// Queries the `bank` module for the balance of `denom` held by `address`.
+    async fn get_balance(&self, address: String, denom: String) -> ChainResult<U256>;
+}
+
+/// gRPC-backed provider for the CosmWasm module and the handful of other Cosmos SDK modules
+/// (`auth`, `bank`, `tx`) the relayer and validator need.
+#[derive(Debug, Clone)]
+pub struct WasmGrpcProvider {
+    domain: HyperlaneDomain,
+    connection_conf: crate::ConnectionConf,
+    gas_price: CosmosAmount,
+    locator: ContractLocator,
+    signer: Option<Signer>,
+}
+
+impl WasmGrpcProvider {
+    /// Builds the provider without opening a connection; each query connects to the first
+    /// configured gRPC endpoint.
+    pub fn new(
+        domain: HyperlaneDomain,
+        connection_conf: crate::ConnectionConf,
+        gas_price: CosmosAmount,
+        locator: ContractLocator,
+        signer: Option<Signer>,
+    ) -> ChainResult<Self> {
+        Ok(Self {
+            domain,
+            connection_conf,
+            gas_price,
+            locator,
+            signer,
+        })
+    }
+
+    fn grpc_url(&self) -> ChainResult<String> {
+        self.connection_conf
+            .get_grpc_urls()
+            .first()
+            .cloned()
+            .ok_or_else(|| ChainCommunicationError::from_other_str("no gRPC URLs configured"))
+    }
+
+    fn signer(&self) -> ChainResult<&Signer> {
+        self.signer
+            .as_ref()
+            .ok_or_else(|| ChainCommunicationError::from_other_str("no signer configured"))
+    }
+
+    /// The configured minimum gas price, in the base unit of its denom. `CosmosProvider` uses
+    /// this as the fallback fee-per-gas-unit when the gas-price oracle can't produce an
+    /// estimate, so every fee built for `sign_and_broadcast` is ultimately grounded in this
+    /// value rather than a second, disconnected copy of it.
+    pub fn minimum_gas_price(&self) -> &CosmosAmount {
+        &self.gas_price
+    }
+
+    async fn fetch_base_account(&self, account: &AccountId) -> ChainResult<BaseAccount> {
+        let mut client = AuthQueryClient::connect(self.grpc_url()?)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        let response = client
+            .account(QueryAccountRequest {
+                address: account.to_string(),
+            })
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .into_inner();
+
+        let account_info = response
+            .account
+            .ok_or_else(|| ChainCommunicationError::from_other_str("no account info returned"))?;
+
+        BaseAccount::decode(account_info.value.as_slice()).map_err(ChainCommunicationError::from_other)
+    }
+
+    /// Queries the `auth` module for `account`'s current on-chain sequence number. Used by
+    /// `SequenceManager` to seed its cache the first time an account is seen, and to reconcile
+    /// after a broadcast fails with a sequence mismatch.
+    pub async fn account_sequence(&self, account: &AccountId) -> ChainResult<SequenceNumber> {
+        Ok(self.fetch_base_account(account).await?.sequence)
+    }
+
+    /// Signs and broadcasts a transaction carrying `msgs`, paying `fee_amount` and using
+    /// `sequence` as its nonce. This is the entry point `CosmosProvider::submit_transaction`
+    /// calls after reserving a sequence number via `with_sequence`, so callers never assemble a
+    /// transaction's nonce or signature themselves.
+    ///
+    /// Returns the broadcast transaction's hash and its raw signed bytes, the latter of which
+    /// `CosmosProvider::track_pending_transaction` caches as the resubmission queue's template
+    /// for fee escalation.
+    pub async fn sign_and_broadcast(
+        &self,
+        msgs: Vec<Any>,
+        gas_limit: u64,
+        fee_amount: Vec<Coin>,
+        sequence: SequenceNumber,
+    ) -> ChainResult<(H256, Vec<u8>)> {
+        let signer = self.signer()?;
+        let signing_key = SigningKey::from_slice(&signer.private_key)
+            .map_err(ChainCommunicationError::from_other)?;
+        let sender: AccountId = signer
+            .address
+            .parse()
+            .map_err(ChainCommunicationError::from_other)?;
+        let account = self.fetch_base_account(&sender).await?;
+
+        let body = TxBody::new(msgs, "", 0u32);
+        let mut fee_amount = fee_amount.into_iter();
+        let mut fee = Fee::from_amount_and_gas(
+            fee_amount
+                .next()
+                .ok_or_else(|| ChainCommunicationError::from_other_str("fee_amount must not be empty"))?,
+            gas_limit,
+        );
+        fee.amount.extend(fee_amount);
+        let signer_info = SignerInfo::single_direct(Some(signing_key.public_key()), sequence);
+        let auth_info = signer_info.auth_info(fee);
+
+        let chain_id = self
+            .domain
+            .to_string()
+            .parse()
+            .map_err(ChainCommunicationError::from_other)?;
+        let sign_doc = SignDoc::new(&body, &auth_info, &chain_id, account.account_number)
+            .map_err(ChainCommunicationError::from_other)?;
+        let raw_tx: RawTx = sign_doc
+            .sign(&signing_key)
+            .map_err(ChainCommunicationError::from_other)?;
+        let tx_bytes = raw_tx.to_bytes().map_err(ChainCommunicationError::from_other)?;
+
+        let hash = self.broadcast_raw(tx_bytes.clone()).await?;
+        Ok((hash, tx_bytes))
+    }
+
+    async fn broadcast_raw(&self, tx_bytes: Vec<u8>) -> ChainResult<H256> {
+        let mut client = TxServiceClient::connect(self.grpc_url()?)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+
+        let response = client
+            .broadcast_tx(BroadcastTxRequest {
+                tx_bytes,
+                mode: BroadcastMode::Sync as i32,
+            })
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .into_inner();
+
+        let tx_response = response
+            .tx_response
+            .ok_or_else(|| ChainCommunicationError::from_other_str("no tx_response returned"))?;
+
+        if tx_response.code != 0 {
+            return Err(ChainCommunicationError::from_other_str(&format!(
+                "broadcast failed with code {}: {}",
+                tx_response.code, tx_response.raw_log
+            )));
+        }
+
+        tx_response
+            .txhash
+            .parse()
+            .map_err(ChainCommunicationError::from_other)
+    }
+}
+
+#[async_trait::async_trait]
+impl WasmProvider for WasmGrpcProvider {
+    async fn wasm_contract_info(&self) -> ChainResult<()> {
+        let contract = AccountId::new(
+            &self.connection_conf.get_bech32_prefix(),
+            self.locator.address.as_bytes(),
+        )
+        .map_err(ChainCommunicationError::from_other)?;
+        let mut client = WasmQueryClient::connect(self.grpc_url()?)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        client
+            .contract_info(QueryContractInfoRequest {
+                address: contract.to_string(),
+            })
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        Ok(())
+    }
+
+    async fn get_balance(&self, address: String, denom: String) -> ChainResult<U256> {
+        let mut client = BankQueryClient::connect(self.grpc_url()?)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        let balance = client
+            .balance(QueryBalanceRequest { address, denom })
+            .await
+            .map_err(ChainCommunicationError::from_other)?
+            .into_inner()
+            .balance
+            .ok_or_else(|| ChainCommunicationError::from_other_str("no balance returned"))?;
+        U256::from_dec_str(&balance.amount).map_err(ChainCommunicationError::from_other)
+    }
+}
@@ -0,0 +1,16 @@
+//! Cosmos SDK chain support for Hyperlane.
+
+mod address;
+mod amount;
+mod config;
+mod error;
+pub mod grpc;
+pub mod providers;
+mod signer;
+
+pub use address::{CosmosAccountId, CosmosAddress};
+pub use amount::CosmosAmount;
+pub use config::{ConnectionConf, DenomConfig, NativeToken, RawCosmosAmount, ResubmissionConfig};
+pub use error::HyperlaneCosmosError;
+pub use providers::CosmosProvider;
+pub use signer::Signer;
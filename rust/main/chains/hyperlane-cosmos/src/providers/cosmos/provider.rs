@@ -1,4 +1,7 @@
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use async_trait::async_trait;
 use cosmrs::cosmwasm::MsgExecuteContract;
@@ -11,7 +14,7 @@ use tendermint::hash::Algorithm;
 use tendermint::Hash;
 use tendermint_rpc::{client::CompatMode, Client, HttpClient};
 use time::OffsetDateTime;
-use tracing::{error, warn};
+use tracing::{debug, error, warn};
 
 use crypto::decompress_public_key;
 use hyperlane_core::{
@@ -32,6 +35,338 @@ const ATTO_EXPONENT: u32 = 18;
 /// Injective public key type URL for protobuf Any
 const INJECTIVE_PUBLIC_KEY_TYPE_URL: &str = "/injective.crypto.v1beta1.ethsecp256k1.PubKey";
 
+/// Default number of trailing blocks sampled when no window size is configured.
+const DEFAULT_GAS_PRICE_SAMPLE_WINDOW: u64 = 20;
+
+/// How long a sampled gas price estimate may be served from cache before it's refreshed.
+const GAS_PRICE_CACHE_TTL: Duration = Duration::from_secs(15);
+
+/// A single cached gas price estimate, keyed by the percentiles it was computed for.
+#[derive(Debug, Clone)]
+struct GasPriceSample {
+    fetched_at: Instant,
+    percentiles: Vec<f64>,
+    prices: Vec<U256>,
+}
+
+/// Short-TTL cache for [`CosmosProvider::estimate_gas_price`] so repeated callers (e.g. a fee
+/// builder consulting the oracle on every broadcast) don't each trigger a fresh block scan.
+#[derive(Debug, Default)]
+struct GasPriceOracleCache(Mutex<Option<GasPriceSample>>);
+
+impl GasPriceOracleCache {
+    fn get(&self, percentiles: &[f64]) -> Option<Vec<U256>> {
+        let guard = self.0.lock().expect("gas price cache lock poisoned");
+        guard.as_ref().and_then(|sample| {
+            let fresh = sample.fetched_at.elapsed() < GAS_PRICE_CACHE_TTL;
+            let same_request = sample.percentiles == percentiles;
+            (fresh && same_request).then(|| sample.prices.clone())
+        })
+    }
+
+    fn set(&self, percentiles: &[f64], prices: Vec<U256>) {
+        let mut guard = self.0.lock().expect("gas price cache lock poisoned");
+        *guard = Some(GasPriceSample {
+            fetched_at: Instant::now(),
+            percentiles: percentiles.to_vec(),
+            prices,
+        });
+    }
+}
+
+/// Caches the next sequence number (nonce) to hand out per signer account, so concurrent
+/// submissions through `WasmGrpcProvider` don't race the chain and trigger `account sequence
+/// mismatch` errors. This borrows the nonce-manager-middleware idea from ethers-rs.
+///
+/// Locking is keyed per account rather than global: each account gets its own
+/// `tokio::sync::Mutex`, held across the `.await` that reconciles against the chain-reported
+/// sequence so two concurrent callers for the *same* account can't both seed from a stale value.
+/// The outer `std::sync::Mutex` only guards inserting that per-account lock into the map, so
+/// submissions for *different* accounts never block on each other.
+#[derive(Debug, Default)]
+struct SequenceManager {
+    accounts: Mutex<HashMap<AccountId, Arc<tokio::sync::Mutex<Option<SequenceNumber>>>>>,
+}
+
+impl SequenceManager {
+    /// Returns the per-account lock, inserting a fresh one if `account` hasn't been seen yet.
+    fn account_lock(&self, account: &AccountId) -> Arc<tokio::sync::Mutex<Option<SequenceNumber>>> {
+        let mut accounts = self.accounts.lock().expect("sequence manager lock poisoned");
+        accounts
+            .entry(account.clone())
+            .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(None)))
+            .clone()
+    }
+
+    /// Returns the next sequence number to use for `account`. The first time `account` is
+    /// seen, the cache is seeded from the chain-reported sequence (queried via the gRPC `auth`
+    /// account info on `grpc_provider`); afterwards values are handed out monotonically from
+    /// the cache without hitting the chain.
+    async fn next(
+        &self,
+        account: &AccountId,
+        grpc_provider: &WasmGrpcProvider,
+    ) -> ChainResult<SequenceNumber> {
+        let lock = self.account_lock(account);
+        let mut cached = lock.lock().await;
+        let sequence = match *cached {
+            Some(sequence) => sequence,
+            None => grpc_provider.account_sequence(account).await?,
+        };
+        *cached = Some(sequence + 1);
+        Ok(sequence)
+    }
+
+    /// Forces the cached sequence number for `account` back to the chain-reported value. Call
+    /// this as a recovery path after a broadcast fails with an `account sequence mismatch`
+    /// error, so the next attempt doesn't compound the drift.
+    async fn reset(&self, account: &AccountId, grpc_provider: &WasmGrpcProvider) -> ChainResult<()> {
+        let lock = self.account_lock(account);
+        let mut cached = lock.lock().await;
+        let chain_sequence = grpc_provider.account_sequence(account).await?;
+        *cached = Some(chain_sequence);
+        Ok(())
+    }
+}
+
+/// A transaction that has been broadcast but not yet confirmed, tracked by [`PendingTxQueue`]
+/// so it can be fee-escalated and rebroadcast if it stalls.
+#[derive(Debug, Clone)]
+struct PendingTx {
+    /// Signer account paying for and sequencing this transaction.
+    signer: AccountId,
+    /// Sequence number reused across every escalation, so the replacement supersedes the
+    /// original instead of creating a duplicate.
+    sequence: SequenceNumber,
+    /// The fee amounts last broadcast with this transaction, one `Coin` per denom.
+    fee_amount: Vec<Coin>,
+    /// Raw bytes of the originally broadcast transaction, used as the template for escalation.
+    /// Escalations do not replace them; the current fee is kept in `fee_amount`.
+    raw_tx: Vec<u8>,
+    /// Height at which this transaction (or its most recent replacement) was broadcast.
+    broadcast_at_height: u64,
+    /// Number of times this transaction has been fee-escalated so far.
+    escalations: u32,
+}
+
+/// An instruction to rebroadcast a pending transaction with an escalated fee. Re-signing and
+/// broadcasting is performed by the signing flow in `WasmGrpcProvider`, which reuses
+/// `sequence` so the replacement supersedes `original_hash` instead of creating a duplicate.
+#[derive(Debug, Clone)]
+struct EscalatedTx {
+    original_hash: H256,
+    signer: AccountId,
+    sequence: SequenceNumber,
+    raw_tx: Vec<u8>,
+    escalated_fee_amount: Vec<Coin>,
+}
+
+/// Tracks broadcast-but-unconfirmed Cosmos transactions and, after a configurable number of
+/// blocks without inclusion, surfaces them for rebroadcast with an escalated fee. Draws on the
+/// transaction-queue / gas-escalator designs from the Ethereum ecosystem.
+#[derive(Debug, Default)]
+struct PendingTxQueue {
+    pending: tokio::sync::Mutex<HashMap<H256, PendingTx>>,
+}
+
+impl PendingTxQueue {
+    async fn track(&self, hash: H256, pending: PendingTx) {
+        let mut queue = self.pending.lock().await;
+        queue.insert(hash, pending);
+    }
+
+    /// Number of transactions currently awaiting confirmation or escalation.
+    async fn depth(&self) -> usize {
+        self.pending.lock().await.len()
+    }
+}
+
+/// Multiplies each coin in `fee_amount` by `factor`, capped per-denom at the matching entry in
+/// `ceiling` (by denom). Denoms absent from `ceiling` are left unescalated. A coin below its
+/// ceiling never drops below its original amount; a coin already above its ceiling is lowered
+/// to the ceiling.
+fn escalate_fee(fee_amount: &[Coin], factor: f64, ceiling: &[Coin]) -> Vec<Coin> {
+    fee_amount
+        .iter()
+        .map(|coin| {
+            // A denom with no configured ceiling has no upper bound to respect, so it's left
+            // unescalated rather than multiplied out unbounded.
+            let Some(ceiling_coin) = ceiling.iter().find(|c| c.denom == coin.denom) else {
+                warn!(
+                    denom = %coin.denom,
+                    "no gas price ceiling configured for denom, leaving fee unescalated"
+                );
+                return coin.clone();
+            };
+
+            let escalated = (coin.amount as f64 * factor).round() as u128;
+            // Never let escalation de-escalate below the previous fee (`.max`), but apply that
+            // *before* the ceiling (`.min`) so a fee already at or above the ceiling can't be
+            // pushed back over it.
+            let amount = escalated.max(coin.amount).min(ceiling_coin.amount);
+
+            Coin {
+                denom: coin.denom.clone(),
+                amount,
+            }
+        })
+        .collect()
+}
+
+/// A denomination string used by a Cosmos chain to express token amounts (e.g. `untrn`, `inj`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Denom(String);
+
+impl Denom {
+    fn new(denom: impl Into<String>) -> Self {
+        Self(denom.into())
+    }
+}
+
+/// Decimal handling for a single denomination registered with a [`DenomRegistry`].
+#[derive(Debug, Clone, Copy)]
+struct DenomMetadata {
+    /// Power of ten `convert_fee` multiplies an already-native-unit amount by to reach atto
+    /// (10^-18) units. Precomputed at registry-build time so `convert_fee` never has to decide
+    /// *which* decimals apply: for a denom with no `conversion_rate` this is derived from the
+    /// denom's own decimals (it's already in "native" terms); for a denom with a
+    /// `conversion_rate`, `convert_fee` converts into native-token units first, so this must be
+    /// derived from the *native* token's decimals instead.
+    atto_exponent: u32,
+    /// Rate used to convert this denom's base unit into the chain's native token before
+    /// scaling by `atto_exponent`. `None` means the denom's amount is already expressed in
+    /// native-token base units (e.g. the native token itself).
+    conversion_rate: Option<f64>,
+}
+
+/// Maps every denom a chain may quote fees in to the decimal precision (and, for non-native
+/// denoms, conversion rate) needed to normalize it into a common atto (10^-18) unit.
+///
+/// Built once from `ConnectionConf` so chains that legitimately pay gas in more than one denom
+/// (e.g. Injective's 10^-18 `inj` alongside Neutron/Osmosis-style 10^-6 `untrn`/`uosmo`) don't
+/// lose fee data in `convert_fee`.
+#[derive(Debug, Clone)]
+struct DenomRegistry(HashMap<Denom, DenomMetadata>);
+
+impl DenomRegistry {
+    fn from_connection_conf(conf: &ConnectionConf) -> Self {
+        let mut registry = HashMap::new();
+
+        let native_token = conf.get_native_token();
+        let native_decimals = clamp_decimals(&native_token.denom, native_token.decimals);
+        registry.insert(
+            Denom::new(native_token.denom.clone()),
+            DenomMetadata {
+                atto_exponent: ATTO_EXPONENT - native_decimals,
+                conversion_rate: None,
+            },
+        );
+
+        for denom in conf.get_additional_fee_denoms() {
+            let atto_exponent = match denom.conversion_rate {
+                // Already converted into native-token units below, so atto-scale by the
+                // *native* token's decimals rather than this denom's own.
+                Some(_) => ATTO_EXPONENT - native_decimals,
+                None => ATTO_EXPONENT - clamp_decimals(&denom.denom, denom.decimals),
+            };
+            registry.insert(
+                Denom::new(denom.denom.clone()),
+                DenomMetadata {
+                    atto_exponent,
+                    conversion_rate: denom.conversion_rate,
+                },
+            );
+        }
+
+        Self(registry)
+    }
+
+    fn get(&self, denom: &str) -> Option<&DenomMetadata> {
+        self.0.get(&Denom::new(denom))
+    }
+
+    fn contains(&self, denom: &str) -> bool {
+        self.0.contains_key(&Denom::new(denom))
+    }
+}
+
+/// Clamps a configured denom's decimal count to `[0, ATTO_EXPONENT]`, logging a warning if it
+/// was out of range. An operator-supplied `decimals` greater than 18 would otherwise underflow
+/// the `ATTO_EXPONENT - decimals` computation and panic in `convert_fee` on every transaction.
+fn clamp_decimals(denom: &str, decimals: u32) -> u32 {
+    if decimals > ATTO_EXPONENT {
+        warn!(
+            denom,
+            decimals, "denom decimals exceed the atto exponent, clamping to 18"
+        );
+        ATTO_EXPONENT
+    } else {
+        decimals
+    }
+}
+
+/// Converts a `U256` into a `u128`, saturating at `u128::MAX` instead of panicking on overflow.
+/// Values here ultimately derive from `convert_fee`, which scales an arbitrary (and
+/// attacker-influenced, since it comes from an on-chain transaction's fee) `Coin.amount` by up
+/// to 10^18 -- easily large enough to exceed `u128::MAX` and make `U256::as_u128` panic.
+fn saturating_u256_to_u128(value: U256) -> u128 {
+    value.min(U256::from(u128::MAX)).as_u128()
+}
+
+/// Returns whether `error` represents a Cosmos SDK `account sequence mismatch` broadcast
+/// failure (the chain's ante handler reports this in its error message), as opposed to some
+/// other broadcast failure that a sequence reset wouldn't fix.
+fn is_sequence_mismatch(error: &ChainCommunicationError) -> bool {
+    error.to_string().contains("account sequence mismatch")
+}
+
+/// How long a [`ChainInfo`] snapshot from `get_chain_metrics` may be served from cache before
+/// it's refreshed, so metrics scrapers polling on a tight interval don't hammer the RPC node.
+const CHAIN_METRICS_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// Short-TTL cache for [`CosmosProvider::get_chain_metrics`].
+#[derive(Debug, Default)]
+struct ChainMetricsCache(Mutex<Option<(Instant, ChainInfo)>>);
+
+impl ChainMetricsCache {
+    fn get(&self) -> Option<ChainInfo> {
+        let guard = self.0.lock().expect("chain metrics cache lock poisoned");
+        guard
+            .as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < CHAIN_METRICS_CACHE_TTL)
+            .map(|(_, info)| info.clone())
+    }
+
+    fn set(&self, info: ChainInfo) {
+        let mut guard = self.0.lock().expect("chain metrics cache lock poisoned");
+        *guard = Some((Instant::now(), info));
+    }
+}
+
+/// A structured summary of a single decoded message within a transaction, produced by
+/// [`CosmosProvider::decode_messages`]. `TxnInfo` (defined in `hyperlane_core`, not this crate)
+/// only has room for one `recipient`, so `get_txn_by_hash` can surface at most the first
+/// value-transferring message through it; callers that need every message in a (possibly
+/// multi-message) transaction should call [`CosmosProvider::get_decoded_messages`] directly.
+#[derive(Debug, Clone)]
+pub enum DecodedMessage {
+    /// A `/cosmwasm.wasm.v1.MsgExecuteContract` call against `contract`.
+    ExecuteContract { contract: H256 },
+    /// A `/cosmos.bank.v1beta1.MsgSend` transferring `amount` from `from` to `to`.
+    Send { from: H256, to: H256, amount: U256 },
+}
+
+impl DecodedMessage {
+    /// The address most relevant to index this message under: the contract for an execute
+    /// message, or the recipient for a send.
+    fn recipient(&self) -> H256 {
+        match self {
+            DecodedMessage::ExecuteContract { contract } => *contract,
+            DecodedMessage::Send { to, .. } => *to,
+        }
+    }
+}
+
 /// Abstraction over a connection to a Cosmos chain
 #[derive(Debug, Clone)]
 pub struct CosmosProvider {
@@ -39,6 +374,11 @@ pub struct CosmosProvider {
     connection_conf: ConnectionConf,
     grpc_provider: WasmGrpcProvider,
     rpc_client: CosmosRpcClient,
+    gas_price_cache: Arc<GasPriceOracleCache>,
+    denom_registry: DenomRegistry,
+    sequence_manager: Arc<SequenceManager>,
+    chain_metrics_cache: Arc<ChainMetricsCache>,
+    pending_tx_queue: Arc<PendingTxQueue>,
 }
 
 impl CosmosProvider {
@@ -58,12 +398,18 @@ impl CosmosProvider {
             signer,
         )?;
         let rpc_client = CosmosRpcClient::new(&conf)?;
+        let denom_registry = DenomRegistry::from_connection_conf(&conf);
 
         Ok(Self {
             domain,
             connection_conf: conf,
             grpc_provider,
             rpc_client,
+            gas_price_cache: Arc::new(GasPriceOracleCache::default()),
+            denom_registry,
+            sequence_manager: Arc::new(SequenceManager::default()),
+            chain_metrics_cache: Arc::new(ChainMetricsCache::default()),
+            pending_tx_queue: Arc::new(PendingTxQueue::default()),
         })
     }
 
@@ -72,6 +418,391 @@ impl CosmosProvider {
         &self.grpc_provider
     }
 
+    /// Returns the next sequence number the signing flow in `WasmGrpcProvider` should use for
+    /// `signer`, before broadcasting. Hands out monotonically increasing values for in-flight
+    /// transactions, reconciling against the chain-reported sequence the first time `signer`
+    /// is seen.
+    pub async fn with_sequence(&self, signer: &AccountId) -> ChainResult<SequenceNumber> {
+        self.sequence_manager.next(signer, &self.grpc_provider).await
+    }
+
+    /// Resets the cached sequence number for `signer` to the chain-reported value. Call this
+    /// as a recovery path when a broadcast fails with an `account sequence mismatch` error.
+    pub async fn reset_sequence(&self, signer: &AccountId) -> ChainResult<()> {
+        self.sequence_manager.reset(signer, &self.grpc_provider).await
+    }
+
+    /// Registers a just-broadcast transaction with the resubmission queue so it can be
+    /// fee-escalated if it stalls. `submit_transaction` calls this immediately after a
+    /// successful broadcast.
+    pub async fn track_pending_transaction(
+        &self,
+        hash: H256,
+        signer: AccountId,
+        sequence: SequenceNumber,
+        fee_amount: Vec<Coin>,
+        raw_tx: Vec<u8>,
+        broadcast_at_height: u64,
+    ) {
+        self.pending_tx_queue
+            .track(
+                hash,
+                PendingTx {
+                    signer,
+                    sequence,
+                    fee_amount,
+                    raw_tx,
+                    broadcast_at_height,
+                    escalations: 0,
+                },
+            )
+            .await;
+    }
+
+    /// Number of transactions currently tracked by the resubmission queue, for metrics scrapers
+    /// to surface as queue depth.
+    pub async fn pending_tx_queue_depth(&self) -> usize {
+        self.pending_tx_queue.depth().await
+    }
+
+    /// Polls the resubmission queue: confirmed transactions are dropped, stalled ones are
+    /// escalated and returned for the caller to re-sign and rebroadcast, and any whose sequence
+    /// has been consumed on-chain by a different hash are dropped outright rather than
+    /// escalated, since a competing broadcast already superseded them.
+    ///
+    /// A transaction is escalated once it has waited `block_wait_threshold` blocks without
+    /// being included, up to `max_retries` times, with its fee multiplied by
+    /// `escalation_factor` and capped at the configured gas-price ceiling on each escalation.
+    pub async fn poll_pending_transactions(&self) -> ChainResult<Vec<EscalatedTx>> {
+        let escalation_factor = self.connection_conf.get_gas_escalation_factor();
+        let max_retries = self.connection_conf.get_max_resubmission_retries();
+        let block_wait_threshold = self.connection_conf.get_resubmission_block_wait_threshold();
+        let gas_price_ceiling = self.connection_conf.get_gas_price_ceiling();
+
+        let current_height = self
+            .rpc_client
+            .get_latest_block()
+            .await?
+            .block
+            .header
+            .height
+            .value();
+
+        let hashes: Vec<H256> = {
+            let queue = self.pending_tx_queue.pending.lock().await;
+            queue.keys().copied().collect()
+        };
+
+        let mut to_drop = Vec::new();
+        let mut to_escalate = Vec::new();
+
+        for hash in hashes {
+            let Ok(tendermint_hash) = Hash::from_bytes(Algorithm::Sha256, hash.as_bytes()) else {
+                continue;
+            };
+
+            if self.rpc_client.get_tx_by_hash(tendermint_hash).await.is_ok() {
+                // Included on-chain: nothing left to do.
+                to_drop.push(hash);
+                continue;
+            }
+
+            let pending = {
+                let queue = self.pending_tx_queue.pending.lock().await;
+                queue.get(&hash).cloned()
+            };
+            let Some(pending) = pending else { continue };
+
+            match self.grpc_provider.account_sequence(&pending.signer).await {
+                Ok(chain_sequence) if chain_sequence > pending.sequence => {
+                    // A different, already-confirmed broadcast consumed this sequence number;
+                    // ours can never land.
+                    to_drop.push(hash);
+                }
+                Ok(_) => {
+                    let waited = current_height.saturating_sub(pending.broadcast_at_height);
+                    if waited >= block_wait_threshold && pending.escalations < max_retries {
+                        to_escalate.push(hash);
+                    }
+                }
+                Err(error) => {
+                    warn!(?hash, ?error, "failed to check on-chain sequence for pending tx");
+                }
+            }
+        }
+
+        let mut queue = self.pending_tx_queue.pending.lock().await;
+        for hash in to_drop {
+            queue.remove(&hash);
+        }
+
+        let mut escalated = Vec::new();
+        for hash in to_escalate {
+            let Some(pending) = queue.get_mut(&hash) else {
+                continue;
+            };
+
+            let escalated_fee_amount =
+                escalate_fee(&pending.fee_amount, escalation_factor, &gas_price_ceiling);
+            if escalated_fee_amount == pending.fee_amount {
+                // Already at the ceiling; leave it queued, nothing more we can do for it.
+                continue;
+            }
+
+            pending.fee_amount = escalated_fee_amount.clone();
+            pending.broadcast_at_height = current_height;
+            pending.escalations += 1;
+
+            escalated.push(EscalatedTx {
+                original_hash: hash,
+                signer: pending.signer.clone(),
+                sequence: pending.sequence,
+                raw_tx: pending.raw_tx.clone(),
+                escalated_fee_amount,
+            });
+        }
+
+        Ok(escalated)
+    }
+
+    /// Signs and broadcasts a transaction carrying `msgs`, and starts tracking it in the
+    /// resubmission queue. This is the signing/broadcast entry point the rest of the crate
+    /// should call instead of going through `WasmGrpcProvider` directly: it's what actually
+    /// threads `SequenceManager` and `PendingTxQueue` into a real broadcast, rather than
+    /// leaving them as unreachable subsystems.
+    ///
+    /// The fee is built from the gas-price oracle's median (`estimate_gas_price(&[0.5])`),
+    /// falling back to the configured `minimum_gas_price` if the oracle can't produce an
+    /// estimate (e.g. too few blocks sampled yet).
+    pub async fn submit_transaction(
+        &self,
+        signer: &AccountId,
+        msgs: Vec<Any>,
+        gas_limit: u64,
+    ) -> ChainResult<H256> {
+        let fee_amount = self.build_fee_amount(gas_limit).await?;
+        let sequence = self.with_sequence(signer).await?;
+
+        let current_height = self
+            .rpc_client
+            .get_latest_block()
+            .await?
+            .block
+            .header
+            .height
+            .value();
+
+        let broadcast = self
+            .grpc_provider
+            .sign_and_broadcast(msgs, gas_limit, fee_amount.clone(), sequence)
+            .await;
+
+        let (hash, raw_tx) = match broadcast {
+            Ok(result) => result,
+            Err(error) => {
+                // Only an `account sequence mismatch` means our cached sequence has drifted
+                // from the chain; any other broadcast failure shouldn't trigger a reset, and if
+                // the reset itself fails it must not shadow the original error the caller needs
+                // to see.
+                if is_sequence_mismatch(&error) {
+                    if let Err(reset_error) = self.reset_sequence(signer).await {
+                        warn!(
+                            ?reset_error,
+                            original_error = ?error,
+                            "failed to reset cached sequence after a sequence mismatch"
+                        );
+                    }
+                }
+                return Err(error);
+            }
+        };
+
+        self.track_pending_transaction(
+            hash,
+            signer.clone(),
+            sequence,
+            fee_amount,
+            raw_tx,
+            current_height,
+        )
+        .await;
+
+        Ok(hash)
+    }
+
+    /// Builds the total fee for a transaction with `gas_limit`, from the gas-price oracle's
+    /// median per-gas-unit estimate, falling back to the configured `minimum_gas_price` if the
+    /// oracle yields nothing. Both are *per-gas-unit* prices, so the chosen price is multiplied
+    /// by `gas_limit` to get the total amount `Fee::from_amount_and_gas` expects as its `Coin` --
+    /// that constructor wraps the coin as the total fee verbatim, it doesn't scale it by gas
+    /// itself.
+    async fn build_fee_amount(&self, gas_limit: u64) -> ChainResult<Vec<Coin>> {
+        let minimum_gas_price = self.grpc_provider.minimum_gas_price();
+        let gas_limit = U256::from(gas_limit);
+
+        let oracle_amount = self.estimate_gas_price(&[0.5]).await.ok().and_then(|prices| {
+            let atto_price_per_gas = *prices.first()?;
+            if atto_price_per_gas.is_zero() {
+                return None;
+            }
+            // `estimate_gas_price` reports atto-scaled prices (see `convert_fee`), so the total
+            // must be scaled back down to `minimum_gas_price.denom`'s own base units before it
+            // can be used as a `Coin.amount` in that denom.
+            let atto_exponent = self.denom_registry.get(&minimum_gas_price.denom)?.atto_exponent;
+            let atto_total_fee = atto_price_per_gas * gas_limit;
+            Some(atto_total_fee / U256::from(10u128.pow(atto_exponent)))
+        });
+
+        let amount = match oracle_amount {
+            Some(amount) => saturating_u256_to_u128(amount),
+            None => saturating_u256_to_u128(minimum_gas_price.amount * gas_limit),
+        };
+
+        Ok(vec![Coin {
+            denom: minimum_gas_price
+                .denom
+                .parse()
+                .map_err(ChainCommunicationError::from_other)?,
+            amount,
+        }])
+    }
+
+    /// Spawns a background task that periodically polls the resubmission queue
+    /// (`poll_pending_transactions`) and rebroadcasts any transaction it escalates. Mirrors the
+    /// gas-escalator pattern from the Ethereum ecosystem, where escalation runs on a timer
+    /// independent of any single submission call.
+    pub fn spawn_resubmission_loop(&self, poll_interval: Duration) -> tokio::task::JoinHandle<()> {
+        let provider = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            loop {
+                interval.tick().await;
+
+                let escalated = match provider.poll_pending_transactions().await {
+                    Ok(escalated) => escalated,
+                    Err(error) => {
+                        warn!(?error, "failed to poll pending transaction queue");
+                        continue;
+                    }
+                };
+
+                for tx in escalated {
+                    // The fee is encoded inside the signed bytes, so escalating it requires
+                    // re-signing rather than rebroadcasting `tx.raw_tx` verbatim. Reusing
+                    // `tx.sequence` is what makes the re-signed transaction supersede the
+                    // original instead of creating a duplicate.
+                    let decoded = match Tx::from_bytes(&tx.raw_tx) {
+                        Ok(decoded) => decoded,
+                        Err(error) => {
+                            warn!(?error, original_hash = ?tx.original_hash, "failed to decode pending tx for escalation");
+                            continue;
+                        }
+                    };
+                    let gas_limit = decoded.auth_info.fee.gas_limit;
+
+                    if let Err(error) = provider
+                        .grpc_provider
+                        .sign_and_broadcast(
+                            decoded.body.messages,
+                            gas_limit,
+                            tx.escalated_fee_amount.clone(),
+                            tx.sequence,
+                        )
+                        .await
+                    {
+                        warn!(
+                            ?error,
+                            original_hash = ?tx.original_hash,
+                            "failed to rebroadcast fee-escalated transaction"
+                        );
+                    }
+                }
+            }
+        })
+    }
+
+    /// Estimates a live gas price by sampling the most recent blocks, modeled on Ethereum's
+    /// `eth_feeHistory`. `build_fee_amount` consults this instead of the static
+    /// `minimum_gas_price` when building fees.
+    ///
+    /// For each of the last `window` blocks (`ConnectionConf::get_gas_price_oracle_window_size`,
+    /// falling back to [`DEFAULT_GAS_PRICE_SAMPLE_WINDOW`]) we collect the effective gas price
+    /// (`fee / gas_limit`) of every transaction, sort them, and for each requested percentile
+    /// `p` pick the value at index `ceil(p * len) - 1`. Blocks that contain no gas-paying
+    /// transactions are skipped entirely. The resulting per-block percentile values are then
+    /// averaged across the window. Results are cached for [`GAS_PRICE_CACHE_TTL`].
+    pub async fn estimate_gas_price(&self, percentiles: &[f64]) -> ChainResult<Vec<U256>> {
+        if let Some(cached) = self.gas_price_cache.get(percentiles) {
+            return Ok(cached);
+        }
+
+        let window = self
+            .connection_conf
+            .get_gas_price_oracle_window_size()
+            .unwrap_or(DEFAULT_GAS_PRICE_SAMPLE_WINDOW);
+
+        let latest = self.rpc_client.get_latest_block().await?;
+        let latest_height = latest.block.header.height.value();
+        let earliest_height = (latest_height + 1).saturating_sub(window).max(1);
+
+        let mut per_block_percentiles = Vec::new();
+        for height in earliest_height..=latest_height {
+            let block = self.rpc_client.get_block(height).await?;
+            if let Some(sampled) = self.sample_block_gas_price_percentiles(&block.block, percentiles) {
+                per_block_percentiles.push(sampled);
+            }
+        }
+
+        let prices = average_percentiles(&per_block_percentiles, percentiles.len());
+        self.gas_price_cache.set(percentiles, prices.clone());
+        Ok(prices)
+    }
+
+    /// Computes the requested gas price percentiles for a single block, or `None` if the block
+    /// contains no transactions paying gas in a denomination registered with the
+    /// `DenomRegistry`.
+    fn sample_block_gas_price_percentiles(
+        &self,
+        block: &tendermint::block::Block,
+        percentiles: &[f64],
+    ) -> Option<Vec<U256>> {
+        let mut gas_prices: Vec<U256> = block
+            .data
+            .iter()
+            .filter_map(|raw_tx| Tx::from_bytes(raw_tx).ok())
+            .filter_map(|tx| {
+                let gas_limit = U256::from(tx.auth_info.fee.gas_limit);
+                if gas_limit.is_zero() {
+                    return None;
+                }
+                let fee = tx
+                    .auth_info
+                    .fee
+                    .amount
+                    .iter()
+                    .map(|c| self.convert_fee(c))
+                    .fold(U256::zero(), |acc, v| acc + v);
+                if fee.is_zero() {
+                    return None;
+                }
+                Some(fee / gas_limit)
+            })
+            .collect();
+
+        if gas_prices.is_empty() {
+            return None;
+        }
+
+        gas_prices.sort();
+
+        Some(
+            percentiles
+                .iter()
+                .map(|p| percentile_value(&gas_prices, *p))
+                .collect(),
+        )
+    }
+
     fn search_payer_in_signer_infos(
         &self,
         signer_infos: &[SignerInfo],
@@ -196,91 +927,369 @@ impl CosmosProvider {
         Ok((sender, nonce))
     }
 
-    /// Extract contract address from transaction.
-    /// Assumes that there is only one `MsgExecuteContract` message in the transaction
-    fn contract(tx: &Tx, tx_hash: &H256) -> ChainResult<H256> {
+    /// Fetches `hash`'s transaction and decodes every recognized value-transferring message it
+    /// contains. `get_txn_by_hash` only surfaces the first such message (`TxnInfo::recipient`
+    /// has room for exactly one address), so callers that need to act on every message in a
+    /// batched transaction should call this instead of relying on `get_txn_by_hash` alone.
+    pub async fn get_decoded_messages(&self, hash: &H256) -> ChainResult<Vec<DecodedMessage>> {
+        let tendermint_hash = Hash::from_bytes(Algorithm::Sha256, hash.as_bytes())
+            .expect("transaction hash should be of correct size");
+        let response = self.rpc_client.get_tx_by_hash(tendermint_hash).await?;
+        let tx = Tx::from_bytes(&response.tx)?;
+        Self::decode_messages(&tx, hash)
+    }
+
+    /// Decodes every message in `tx.body.messages` whose `type_url` is recognized into a
+    /// [`DecodedMessage`]. Unlike the single-`MsgExecuteContract` assumption this replaces,
+    /// this walks *all* messages so batched txs (e.g. a `MsgSend` alongside a contract call)
+    /// aren't misrepresented. Messages with an unrecognized `type_url` (IBC, governance, ...)
+    /// are skipped rather than failing the whole decode, since they carry no Hyperlane-relevant
+    /// value transfer.
+    fn decode_messages(tx: &Tx, tx_hash: &H256) -> ChainResult<Vec<DecodedMessage>> {
+        use cosmrs::bank::MsgSend;
+        use cosmrs::proto::cosmos::bank::v1beta1::MsgSend as ProtoMsgSend;
         use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract as ProtoMsgExecuteContract;
 
-        let contract_execution_messages = tx
-            .body
+        tx.body
             .messages
             .iter()
-            .filter(|a| a.type_url == "/cosmwasm.wasm.v1.MsgExecuteContract")
-            .cloned()
-            .collect::<Vec<Any>>();
-
-        let contract_execution_messages_len = contract_execution_messages.len();
-        if contract_execution_messages_len > 1 {
-            let msg = "transaction contains multiple contract execution messages, we are indexing the first entry only";
-            warn!(?tx_hash, ?contract_execution_messages, msg);
-            Err(ChainCommunicationError::CustomError(msg.to_owned()))?
-        }
-
-        let any = contract_execution_messages.first().ok_or_else(|| {
-            let msg = "could not find contract execution message";
-            warn!(?tx_hash, msg);
-            ChainCommunicationError::from_other_str(msg)
-        })?;
-        let proto =
-            ProtoMsgExecuteContract::from_any(any).map_err(Into::<HyperlaneCosmosError>::into)?;
-        let msg = MsgExecuteContract::try_from(proto)?;
-        let contract = H256::try_from(CosmosAccountId::new(&msg.contract))?;
-        Ok(contract)
+            .filter_map(|any| match any.type_url.as_str() {
+                "/cosmwasm.wasm.v1.MsgExecuteContract" => Some((|| -> ChainResult<DecodedMessage> {
+                    let proto = ProtoMsgExecuteContract::from_any(any)
+                        .map_err(Into::<HyperlaneCosmosError>::into)?;
+                    let msg = MsgExecuteContract::try_from(proto)?;
+                    let contract = H256::try_from(CosmosAccountId::new(&msg.contract))?;
+                    Ok(DecodedMessage::ExecuteContract { contract })
+                })()),
+                "/cosmos.bank.v1beta1.MsgSend" => Some((|| -> ChainResult<DecodedMessage> {
+                    let proto = ProtoMsgSend::from_any(any)
+                        .map_err(Into::<HyperlaneCosmosError>::into)?;
+                    let msg = MsgSend::try_from(proto)?;
+                    let from = H256::try_from(CosmosAccountId::new(&msg.from_address))?;
+                    let to = H256::try_from(CosmosAccountId::new(&msg.to_address))?;
+                    let amount = msg
+                        .amount
+                        .iter()
+                        .fold(U256::zero(), |acc, c| acc + U256::from(c.amount));
+                    Ok(DecodedMessage::Send { from, to, amount })
+                })()),
+                other => {
+                    debug!(?tx_hash, type_url = other, "skipping message with unrecognized type_url");
+                    None
+                }
+            })
+            .collect()
     }
 
-    /// Reports if transaction contains fees expressed in unsupported denominations
-    /// The only denomination we support at the moment is the one we express gas minimum price
-    /// in the configuration of a chain. If fees contain an entry in a different denomination,
-    /// we report it in the logs.
+    /// Reports if a transaction contains fees expressed in denominations absent from the
+    /// provider's `DenomRegistry`. Registered denoms are normalized by `convert_fee`;
+    /// anything else is logged so operators can register it instead of silently losing fee
+    /// data.
     fn report_unsupported_denominations(&self, tx: &Tx, tx_hash: &H256) -> ChainResult<()> {
-        let supported_denomination = self.connection_conf.get_minimum_gas_price().denom;
         let unsupported_denominations = tx
             .auth_info
             .fee
             .amount
             .iter()
-            .filter(|c| c.denom.as_ref() != supported_denomination)
+            .filter(|c| !self.denom_registry.contains(c.denom.as_ref()))
             .map(|c| c.denom.as_ref())
             .fold("".to_string(), |acc, denom| acc + ", " + denom);
 
         if !unsupported_denominations.is_empty() {
-            let msg = "transaction contains fees in unsupported denominations, manual intervention is required";
-            warn!(
-                ?tx_hash,
-                ?supported_denomination,
-                ?unsupported_denominations,
-                msg,
-            );
-            Err(ChainCommunicationError::CustomError(msg.to_owned()))?
+            let msg = "transaction contains fees in denominations absent from the registry";
+            warn!(?tx_hash, ?unsupported_denominations, msg);
         }
 
         Ok(())
     }
 
-    /// Converts fees to a common denomination if necessary.
+    /// Normalizes a single fee `Coin` into atto (10^-18) units: of its own denomination, or, for
+    /// a denom with a `conversion_rate`, of the chain's native token.
     ///
     /// Currently, we support Injective, Neutron and Osmosis. Fees in Injective are usually
     /// expressed in `inj` which is 10^-18 of `INJ`, while fees in Neutron and Osmosis are
     /// usually expressed in `untrn` and `uosmo`, respectively, which are 10^-6 of corresponding
-    /// `NTRN` and `OSMO`.
+    /// `NTRN` and `OSMO`. Any chain can register additional denoms via `ConnectionConf`.
     ///
-    /// This function will convert fees expressed in `untrn` and `uosmo` to 10^-18 of `NTRN` and
-    /// `OSMO` and it will keep fees expressed in `inj` as is.
-    ///
-    /// If fees are expressed in an unsupported denomination, they will be ignored.
+    /// Denoms with a `conversion_rate` are first converted into the chain's native token using
+    /// that rate before being scaled to atto units by the native token's `decimals`. Denoms
+    /// absent from the `DenomRegistry` contribute nothing here; `report_unsupported_denominations`
+    /// surfaces those separately.
     fn convert_fee(&self, coin: &Coin) -> U256 {
-        let native_token = self.connection_conf.get_native_token();
-
-        if coin.denom.as_ref() != native_token.denom {
+        let Some(metadata) = self.denom_registry.get(coin.denom.as_ref()) else {
             return U256::zero();
+        };
+
+        let amount_in_denom = match metadata.conversion_rate {
+            Some(rate) => U256::from((coin.amount as f64 * rate).round() as u128),
+            None => U256::from(coin.amount),
+        };
+
+        let coefficient = U256::from(10u128.pow(metadata.atto_exponent));
+
+        amount_in_denom * coefficient
+    }
+}
+
+#[cfg(test)]
+mod fee_escalation_tests {
+    use super::*;
+
+    fn coin(denom: &str, amount: u128) -> Coin {
+        Coin {
+            denom: denom.parse().unwrap(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn escalate_fee_never_exceeds_ceiling() {
+        let fee = vec![coin("untrn", 95)];
+        let ceiling = vec![coin("untrn", 100)];
+
+        // 95 * 2.0 = 190, which must be clamped down to the 100 ceiling, not left at 190.
+        let escalated = escalate_fee(&fee, 2.0, &ceiling);
+        assert_eq!(escalated, vec![coin("untrn", 100)]);
+    }
+
+    #[test]
+    fn escalate_fee_never_de_escalates_below_the_previous_fee() {
+        let fee = vec![coin("untrn", 100)];
+        let ceiling = vec![coin("untrn", 100)];
+
+        // Already at the ceiling; escalating further must hold steady at 100: neither below the
+        // previous fee nor past the ceiling.
+        let escalated = escalate_fee(&fee, 1.5, &ceiling);
+        assert_eq!(escalated, vec![coin("untrn", 100)]);
+    }
+
+    #[test]
+    fn escalate_fee_leaves_unceilinged_denoms_unescalated() {
+        let fee = vec![coin("untrn", 50), coin("uosmo", 50)];
+        let ceiling = vec![coin("untrn", 100)];
+
+        // `uosmo` has no ceiling entry, so it must not be escalated unbounded.
+        let escalated = escalate_fee(&fee, 2.0, &ceiling);
+        assert_eq!(escalated, vec![coin("untrn", 100), coin("uosmo", 50)]);
+    }
+}
+
+#[cfg(test)]
+mod message_decoding_tests {
+    use super::*;
+    use cosmrs::bank::MsgSend;
+    use cosmrs::tx::{Body as TxBody, Fee, Msg, SignerInfo};
+
+    fn account(id: &str) -> AccountId {
+        id.parse().unwrap()
+    }
+
+    fn tx_with_messages(messages: Vec<Any>) -> Tx {
+        let body = TxBody::new(messages, "", 0u32);
+        let fee = Fee::from_amount_and_gas(
+            Coin {
+                denom: "untrn".parse().unwrap(),
+                amount: 1,
+            },
+            100_000u64,
+        );
+        let auth_info = SignerInfo::single_direct(None, 0).auth_info(fee);
+        Tx {
+            body,
+            auth_info,
+            signatures: vec![],
+        }
+    }
+
+    #[test]
+    fn decode_messages_decodes_bank_send() {
+        // Real bech32-checksummed addresses -- a hand-typed placeholder containing `o`, `b`,
+        // `i`, or `1` outside the separator isn't valid bech32 and fails to parse at all.
+        let from = account("cosmos1lla8tl5djra5ct0vnkmzu7xllcu9c6n6e8aw3h");
+        let to = account("cosmos1aj9nf2gvm88mymge5ur3zyxh6m0f7h9zqltr6n");
+        let msg = MsgSend {
+            from_address: from.clone(),
+            to_address: to.clone(),
+            amount: vec![Coin {
+                denom: "untrn".parse().unwrap(),
+                amount: 100,
+            }],
+        };
+        let tx = tx_with_messages(vec![msg.to_any().unwrap()]);
+        let tx_hash = H256::zero();
+
+        let messages = CosmosProvider::decode_messages(&tx, &tx_hash).unwrap();
+
+        assert_eq!(messages.len(), 1);
+        match &messages[0] {
+            DecodedMessage::Send { amount, .. } => assert_eq!(*amount, U256::from(100u64)),
+            other => panic!("expected a decoded Send message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_messages_skips_unrecognized_type_urls() {
+        let any = Any {
+            type_url: "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+            value: vec![],
+        };
+        let tx = tx_with_messages(vec![any]);
+        let tx_hash = H256::zero();
+
+        let messages = CosmosProvider::decode_messages(&tx, &tx_hash).unwrap();
+
+        assert!(messages.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod denom_registry_tests {
+    use super::*;
+    use crate::{DenomConfig, NativeToken, RawCosmosAmount, ResubmissionConfig};
+
+    fn registry_with(
+        native_denom: &str,
+        native_decimals: u32,
+        additional: Vec<DenomConfig>,
+    ) -> DenomRegistry {
+        let conf = ConnectionConf::new(
+            vec!["http://localhost:26657".to_string()],
+            vec!["http://localhost:9090".to_string()],
+            "test".to_string(),
+            native_denom.to_string(),
+            RawCosmosAmount {
+                denom: native_denom.to_string(),
+                amount: "0".to_string(),
+            },
+            NativeToken {
+                denom: native_denom.to_string(),
+                decimals: native_decimals,
+            },
+            additional,
+            None,
+            ResubmissionConfig {
+                escalation_factor: 1.1,
+                max_retries: 3,
+                block_wait_threshold: 5,
+                gas_price_ceiling: vec![],
+            },
+        );
+        DenomRegistry::from_connection_conf(&conf)
+    }
+
+    fn coin(denom: &str, amount: u128) -> Coin {
+        Coin {
+            denom: denom.parse().unwrap(),
+            amount,
         }
+    }
+
+    #[test]
+    fn convert_fee_scales_native_denom_to_atto() {
+        let registry = registry_with("inj", 18, vec![]);
+        let metadata = registry.get("inj").unwrap();
+        assert_eq!(metadata.atto_exponent, 0);
+    }
+
+    #[test]
+    fn convert_fee_applies_conversion_rate_using_native_decimals() {
+        // untrn is 10^-6 of NTRN; NTRN is the native token here at 6 decimals, so a foreign
+        // denom converted into untrn at a 0.5 rate should scale by the *native* exponent (12),
+        // not its own.
+        let registry = registry_with(
+            "untrn",
+            6,
+            vec![DenomConfig {
+                denom: "uosmo".to_string(),
+                decimals: 6,
+                conversion_rate: Some(0.5),
+            }],
+        );
+
+        let provider_metadata = registry.get("uosmo").unwrap();
+        assert_eq!(provider_metadata.atto_exponent, 12);
+
+        let amount = coin("uosmo", 1_000_000);
+        let converted = convert_with_metadata(&amount, provider_metadata);
+        // 1_000_000 * 0.5 = 500_000 untrn-equivalent, scaled by 10^12.
+        assert_eq!(converted, U256::from(500_000u128) * U256::from(10u128.pow(12)));
+    }
+
+    #[test]
+    fn clamp_decimals_prevents_underflow_panic() {
+        // A denom configured with more than 18 decimals must not panic `10u128.pow(..)`.
+        assert_eq!(clamp_decimals("bogus", 30), ATTO_EXPONENT);
+        assert_eq!(clamp_decimals("untrn", 6), 6);
+    }
+
+    fn convert_with_metadata(coin: &Coin, metadata: &DenomMetadata) -> U256 {
+        let amount_in_denom = match metadata.conversion_rate {
+            Some(rate) => U256::from((coin.amount as f64 * rate).round() as u128),
+            None => U256::from(coin.amount),
+        };
+        amount_in_denom * U256::from(10u128.pow(metadata.atto_exponent))
+    }
+}
+
+/// Picks the value at the given percentile from an already-sorted slice, using the
+/// `ceil(p * len) - 1` index convention (clamped to the last element).
+fn percentile_value(sorted: &[U256], percentile: f64) -> U256 {
+    let len = sorted.len();
+    let idx = ((percentile * len as f64).ceil() as usize)
+        .saturating_sub(1)
+        .min(len - 1);
+    sorted[idx]
+}
+
+/// Averages each requested percentile across all sampled blocks. Blocks that yielded no
+/// samples are expected to already have been filtered out by the caller.
+fn average_percentiles(per_block: &[Vec<U256>], percentile_count: usize) -> Vec<U256> {
+    if per_block.is_empty() {
+        return vec![U256::zero(); percentile_count];
+    }
+
+    let block_count = U256::from(per_block.len() as u64);
+    (0..percentile_count)
+        .map(|i| {
+            let sum = per_block
+                .iter()
+                .fold(U256::zero(), |acc, block| acc + block[i]);
+            sum / block_count
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod percentile_tests {
+    use super::*;
+
+    fn prices(values: &[u64]) -> Vec<U256> {
+        values.iter().map(|v| U256::from(*v)).collect()
+    }
+
+    #[test]
+    fn percentile_value_picks_the_median() {
+        let sorted = prices(&[10, 20, 30, 40, 50]);
+        assert_eq!(percentile_value(&sorted, 0.5), U256::from(30u64));
+    }
 
-        let exponent = ATTO_EXPONENT - native_token.decimals;
-        let coefficient = U256::from(10u128.pow(exponent));
+    #[test]
+    fn percentile_value_clamps_to_the_last_element() {
+        let sorted = prices(&[10, 20, 30]);
+        assert_eq!(percentile_value(&sorted, 1.0), U256::from(30u64));
+    }
 
-        let amount_in_native_denom = U256::from(coin.amount);
+    #[test]
+    fn average_percentiles_averages_each_percentile_across_blocks() {
+        let per_block = vec![prices(&[10, 30]), prices(&[20, 50])];
+        let averaged = average_percentiles(&per_block, 2);
+        assert_eq!(averaged, vec![U256::from(15u64), U256::from(40u64)]);
+    }
 
-        amount_in_native_denom * coefficient
+    #[test]
+    fn average_percentiles_returns_zeros_when_no_blocks_sampled() {
+        let averaged = average_percentiles(&[], 2);
+        assert_eq!(averaged, vec![U256::zero(), U256::zero()]);
     }
 }
 
@@ -345,10 +1354,18 @@ impl HyperlaneProvider for CosmosProvider {
 
         let tx = Tx::from_bytes(&response.tx)?;
 
-        let contract = Self::contract(&tx, hash)?;
+        let messages = Self::decode_messages(&tx, hash)?;
+        if messages.len() > 1 {
+            let recipients: Vec<H256> = messages.iter().map(DecodedMessage::recipient).collect();
+            debug!(
+                ?hash,
+                ?recipients,
+                "transaction contains multiple value-transferring messages, indexing primary recipient only"
+            );
+        }
+        let recipient = messages.first().map(DecodedMessage::recipient);
         let (sender, nonce) = self.sender_and_nonce(&tx)?;
 
-        // TODO support multiple denominations for amount
         self.report_unsupported_denominations(&tx, hash)?;
 
         let gas_limit = U256::from(tx.auth_info.fee.gas_limit);
@@ -376,7 +1393,7 @@ impl HyperlaneProvider for CosmosProvider {
             gas_price: Some(gas_price),
             nonce,
             sender,
-            recipient: Some(contract),
+            recipient,
             receipt: Some(TxnReceiptInfo {
                 gas_used: U256::from(response.tx_result.gas_used),
                 cumulative_gas_used: U256::from(response.tx_result.gas_used),
@@ -402,6 +1419,40 @@ impl HyperlaneProvider for CosmosProvider {
     }
 
     async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
-        Ok(None)
+        if let Some(cached) = self.chain_metrics_cache.get() {
+            return Ok(Some(cached));
+        }
+
+        let latest_block = match self.rpc_client.get_latest_block().await {
+            Ok(block) => block,
+            Err(error) => {
+                warn!(?error, "failed to fetch latest block for chain metrics");
+                return Ok(None);
+            }
+        };
+
+        let time: OffsetDateTime = latest_block.block.header.time.into();
+        let latest_block_info = BlockInfo {
+            hash: H256::from_slice(latest_block.block_id.hash.as_bytes()),
+            timestamp: time.unix_timestamp() as u64,
+            number: latest_block.block.header.height.value(),
+        };
+
+        // A stalled gas-price oracle shouldn't prevent the head from being reported.
+        let min_gas_price = match self.estimate_gas_price(&[0.5]).await {
+            Ok(prices) => prices.first().map(|price| saturating_u256_to_u128(*price) as f64),
+            Err(error) => {
+                warn!(?error, "failed to estimate gas price for chain metrics");
+                None
+            }
+        };
+
+        let chain_info = ChainInfo {
+            latest_block: latest_block_info,
+            min_gas_price,
+        };
+
+        self.chain_metrics_cache.set(chain_info.clone());
+        Ok(Some(chain_info))
     }
 }
\ No newline at end of file
@@ -0,0 +1,4 @@
+mod cosmos;
+pub mod rpc;
+
+pub use cosmos::CosmosProvider;
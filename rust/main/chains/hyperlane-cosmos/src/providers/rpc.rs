@@ -0,0 +1,65 @@
+use hyperlane_core::{ChainCommunicationError, ChainResult};
+use tendermint::Hash;
+use tendermint_rpc::endpoint::{block, block_by_hash, tx};
+use tendermint_rpc::{client::CompatMode, Client, HttpClient};
+
+use crate::ConnectionConf;
+
+/// A thin wrapper around `tendermint_rpc::HttpClient`, scoped to the handful of RPC calls the
+/// Cosmos provider needs.
+#[derive(Debug, Clone)]
+pub struct CosmosRpcClient {
+    client: HttpClient,
+}
+
+impl CosmosRpcClient {
+    /// Connects to the first configured RPC endpoint.
+    pub fn new(conf: &ConnectionConf) -> ChainResult<Self> {
+        let url = conf
+            .get_rpc_urls()
+            .first()
+            .ok_or_else(|| ChainCommunicationError::from_other_str("no RPC URLs configured"))?;
+
+        let client = HttpClient::builder(url.parse().map_err(ChainCommunicationError::from_other)?)
+            .compat_mode(CompatMode::V0_37)
+            .build()
+            .map_err(ChainCommunicationError::from_other)?;
+
+        Ok(Self { client })
+    }
+
+    /// Fetches a block by its Tendermint hash.
+    pub async fn get_block_by_hash(&self, hash: Hash) -> ChainResult<block_by_hash::Response> {
+        self.client
+            .block_by_hash(hash)
+            .await
+            .map_err(ChainCommunicationError::from_other)
+    }
+
+    /// Fetches a transaction by its Tendermint hash.
+    pub async fn get_tx_by_hash(&self, hash: Hash) -> ChainResult<tx::Response> {
+        self.client
+            .tx(hash, false)
+            .await
+            .map_err(ChainCommunicationError::from_other)
+    }
+
+    /// Fetches the latest block, used by the gas-price oracle and `get_chain_metrics` to
+    /// determine the current chain head.
+    pub async fn get_latest_block(&self) -> ChainResult<block::Response> {
+        self.client
+            .latest_block()
+            .await
+            .map_err(ChainCommunicationError::from_other)
+    }
+
+    /// Fetches the block at `height`, used by the gas-price oracle to sample trailing blocks.
+    pub async fn get_block(&self, height: u64) -> ChainResult<block::Response> {
+        let height = tendermint::block::Height::try_from(height)
+            .map_err(ChainCommunicationError::from_other)?;
+        self.client
+            .block(height)
+            .await
+            .map_err(ChainCommunicationError::from_other)
+    }
+}
@@ -0,0 +1,19 @@
+/// Key material used to sign outgoing Cosmos transactions.
+#[derive(Debug, Clone)]
+pub struct Signer {
+    /// Bech32-encoded address of the signing account.
+    pub address: String,
+    /// Private key bytes, used to construct a `cosmrs::crypto::secp256k1::SigningKey` when
+    /// signing a transaction.
+    pub(crate) private_key: Vec<u8>,
+}
+
+impl Signer {
+    /// Builds a signer from raw private key bytes and its corresponding bech32 address.
+    pub fn new(address: String, private_key: Vec<u8>) -> Self {
+        Self {
+            address,
+            private_key,
+        }
+    }
+}